@@ -0,0 +1,123 @@
+use agb::{
+    display::{
+        object::{ObjectControl, ObjectStandard, Size},
+        Priority,
+    },
+    number::{FixedNum, Vector2D},
+};
+
+use crate::FixedNumberType;
+
+const NUM_CARETS: usize = 8;
+
+// A short-lived animated effect sprite, decoupled from gameplay entities,
+// used for throw/catch/landing feedback. Named after the doukutsu-style
+// caret effect objects.
+struct Caret<'a> {
+    sprite: ObjectStandard<'a>,
+    position: Vector2D<FixedNumberType>,
+    velocity: Vector2D<FixedNumberType>,
+    tile_id_start: u16,
+    num_frames: u16,
+    use_gravity: bool,
+    frame: i32,
+    lifetime: i32,
+}
+
+impl<'a> Caret<'a> {
+    // advances the caret one frame, returns true once it has expired
+    fn update_frame(&mut self) -> bool {
+        if self.use_gravity {
+            self.velocity.y += FixedNum::new(1) / 16;
+        }
+        self.position += self.velocity;
+        self.frame += 1;
+
+        if self.frame >= self.lifetime {
+            return true;
+        }
+
+        let progress = self.frame * self.num_frames as i32 / self.lifetime;
+        self.sprite
+            .set_tile_id(self.tile_id_start + progress as u16);
+
+        false
+    }
+
+    fn commit_position(&mut self, offset: Vector2D<FixedNumberType>) {
+        let position = (self.position - offset).floor();
+        self.sprite.set_position(position - (4, 4).into());
+        self.sprite.commit();
+    }
+
+    fn hide(&mut self) {
+        self.sprite.hide();
+        self.sprite.commit();
+    }
+}
+
+// A fixed-capacity pool of carets, updated and committed once a frame, with
+// dead entries recycled for the next spawn.
+pub struct Carets<'a> {
+    object: &'a ObjectControl,
+    carets: [Option<Caret<'a>>; NUM_CARETS],
+}
+
+impl<'a> Carets<'a> {
+    pub fn new(object: &'a ObjectControl) -> Self {
+        Carets {
+            object,
+            carets: [None, None, None, None, None, None, None, None],
+        }
+    }
+
+    pub fn spawn(
+        &mut self,
+        position: Vector2D<FixedNumberType>,
+        velocity: Vector2D<FixedNumberType>,
+        tile_id_start: u16,
+        num_frames: u16,
+        lifetime: i32,
+        use_gravity: bool,
+    ) {
+        let slot = match self.carets.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => slot,
+            None => return,
+        };
+
+        let mut sprite = self.object.get_object_standard();
+        sprite.set_priority(Priority::P1);
+        sprite.set_sprite_size(Size::S8x8);
+        sprite.set_tile_id(tile_id_start);
+        sprite.show();
+
+        *slot = Some(Caret {
+            sprite,
+            position,
+            velocity,
+            tile_id_start,
+            num_frames,
+            use_gravity,
+            frame: 0,
+            lifetime,
+        });
+    }
+
+    pub fn update_frame(&mut self) {
+        for slot in self.carets.iter_mut() {
+            let expired = slot.as_mut().map(Caret::update_frame).unwrap_or(false);
+            if expired {
+                if let Some(caret) = slot {
+                    caret.hide();
+                }
+                *slot = None;
+            }
+        }
+    }
+
+    pub fn commit_position(&mut self, offset: Vector2D<FixedNumberType>) {
+        for caret in self.carets.iter_mut().flatten() {
+            caret.commit_position(offset);
+        }
+    }
+}
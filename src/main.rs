@@ -1,6 +1,14 @@
 #![no_std]
 #![no_main]
 
+mod caret;
+mod rng;
+mod sfx;
+
+use caret::Carets;
+use rng::Rng;
+use sfx::MusicBox;
+
 struct Level {
     background: &'static [u16],
     foreground: &'static [u16],
@@ -13,6 +21,9 @@ mod object_tiles {
     pub const WIZARD_JUMP: u16 = 4 * 4;
     pub const WIZARD_FALL_START: u16 = 5 * 4;
     pub const HAT_TILE_START: u16 = 9 * 4;
+    pub const CARET_PUFF_START: u16 = 76;
+    pub const CARET_SPARKLE_START: u16 = 80;
+    pub const CARET_DUST_START: u16 = 84;
     include!(concat!(env!("OUT_DIR"), "/object_sheet.rs"));
 }
 
@@ -21,6 +32,10 @@ mod map_tiles {
         include!(concat!(env!("OUT_DIR"), "/level1.json.rs"));
     }
 
+    pub mod level2 {
+        include!(concat!(env!("OUT_DIR"), "/level2.json.rs"));
+    }
+
     pub mod tilemap {
         include!(concat!(env!("OUT_DIR"), "/tilemap.rs"));
     }
@@ -38,6 +53,7 @@ use agb::{
     },
     input::{self, Button, ButtonController},
     number::{FixedNum, Vector2D},
+    sound::mixer::Mixer,
 };
 
 type FixedNumberType = FixedNum<10>;
@@ -61,34 +77,99 @@ impl<'a> Entity<'a> {
         }
     }
 
-    fn collision_at_point(&mut self, level: &Level, position: Vector2D<FixedNumberType>) -> bool {
-        let left = (position.x.floor() - self.collision_mask.x as i32 / 2) / 8;
-        let right = (position.x.floor() + self.collision_mask.x as i32 / 2) / 8;
-        let top = (position.y.floor() - self.collision_mask.y as i32 / 2) / 8;
-        let bottom = (position.y.floor() + self.collision_mask.y as i32 / 2) / 8;
+    fn bottom(&self) -> FixedNumberType {
+        self.position.y + FixedNumberType::new(self.collision_mask.y as i32) / 2
+    }
+
+    fn collision_at_point(
+        &mut self,
+        level: &Level,
+        platforms: &[Platform],
+        position: Vector2D<FixedNumberType>,
+    ) -> bool {
+        for platform in platforms {
+            if platform.collides_at(position, self.collision_mask) {
+                return true;
+            }
+        }
+
+        let bbox_left = position.x.floor() - self.collision_mask.x as i32 / 2;
+        let bbox_right = position.x.floor() + self.collision_mask.x as i32 / 2;
+        let bbox_top = position.y.floor() - self.collision_mask.y as i32 / 2;
+        let bbox_bottom = position.y.floor() + self.collision_mask.y as i32 / 2;
+
+        let left = bbox_left / 8;
+        let right = bbox_right / 8;
+        let top = bbox_top / 8;
+        let bottom = bbox_bottom / 8;
 
         for x in left..right {
             for y in top..bottom {
-                if level.collides(x, y) {
+                let tile_kind = level.tile_kind(x, y);
+                if tile_kind == TileKind::Solid {
                     return true;
                 }
+                if tile_kind != TileKind::Empty {
+                    // Sample the part of the bounding box that actually falls
+                    // inside this tile, not the query position's own offset
+                    // (which is the same for every tile the box spans and
+                    // bears no relation to the ones it doesn't own).
+                    let tile_left = x * 8;
+                    let tile_top = y * 8;
+                    let x_near = (bbox_left.max(tile_left) - tile_left).clamp(0, 7);
+                    let x_far = (bbox_right.min(tile_left + 7) - tile_left).clamp(0, 7);
+                    let y_local = (bbox_bottom.min(tile_top + 7) - tile_top).clamp(0, 7);
+
+                    let surface = tile_kind
+                        .surface_height(x_near)
+                        .min(tile_kind.surface_height(x_far));
+                    if y_local >= surface {
+                        return true;
+                    }
+                }
             }
         }
         false
     }
 
+    // Walks the entity's bottom-centre up onto a slope it is standing on,
+    // rather than letting it catch on the slope's leading edge.
+    fn snap_to_slope(&mut self, level: &Level) {
+        let mask_bottom = self.position.y.floor() + self.collision_mask.y as i32 / 2;
+        let tile_x = self.position.x.floor() / 8;
+        let tile_y = mask_bottom / 8;
+
+        let tile_kind = level.tile_kind(tile_x, tile_y);
+        if tile_kind == TileKind::Solid || tile_kind == TileKind::Empty {
+            return;
+        }
+
+        let x_local = self.position.x.floor().rem_euclid(8);
+        let surface_y = tile_y * 8 + tile_kind.surface_height(x_local);
+
+        if mask_bottom > surface_y {
+            self.position.y -= FixedNumberType::new(mask_bottom - surface_y);
+        }
+    }
+
     // returns the distance actually moved
-    fn update_position(&mut self, level: &Level) -> Vector2D<FixedNumberType> {
+    fn update_position(
+        &mut self,
+        level: &Level,
+        platforms: &[Platform],
+    ) -> Vector2D<FixedNumberType> {
         let old_position = self.position;
         let x_velocity = (self.velocity.x, 0.into()).into();
-        if !self.collision_at_point(level, self.position + x_velocity) {
+        if !self.collision_at_point(level, platforms, self.position + x_velocity) {
             self.position += x_velocity;
         }
         let y_velocity = (0.into(), self.velocity.y).into();
-        if !self.collision_at_point(level, self.position + y_velocity) {
+        if !self.collision_at_point(level, platforms, self.position + y_velocity) {
             self.position += y_velocity;
         }
 
+        self.snap_to_slope(level);
+
         self.position - old_position
     }
     fn commit_position(&mut self, offset: Vector2D<FixedNumberType>) {
@@ -103,6 +184,102 @@ impl<'a> Entity<'a> {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PlatformMovement {
+    Linear,
+}
+
+// A lift or moving block that ping-pongs between two endpoints and carries
+// whatever is resting on top of it.
+struct Platform {
+    pos1: Vector2D<FixedNumberType>,
+    pos2: Vector2D<FixedNumberType>,
+    speed: FixedNumberType,
+    movement: PlatformMovement,
+    collision_mask: Vector2D<u16>,
+    position: Vector2D<FixedNumberType>,
+    towards_pos2: bool,
+    last_delta: Vector2D<FixedNumberType>,
+}
+
+impl Platform {
+    fn new(
+        pos1: Vector2D<FixedNumberType>,
+        pos2: Vector2D<FixedNumberType>,
+        speed: FixedNumberType,
+        collision_mask: Vector2D<u16>,
+    ) -> Self {
+        Platform {
+            pos1,
+            pos2,
+            speed,
+            movement: PlatformMovement::Linear,
+            collision_mask,
+            position: pos1,
+            towards_pos2: true,
+            last_delta: (0, 0).into(),
+        }
+    }
+
+    // Advances the platform by one frame's worth of movement and returns
+    // the delta, so riders can be carried by the same amount.
+    fn update_frame(&mut self) -> Vector2D<FixedNumberType> {
+        let delta = match self.movement {
+            PlatformMovement::Linear => {
+                let target = if self.towards_pos2 {
+                    self.pos2
+                } else {
+                    self.pos1
+                };
+                let to_target = target - self.position;
+                let distance = to_target.magnitude();
+
+                if distance <= self.speed {
+                    self.towards_pos2 = !self.towards_pos2;
+                    to_target
+                } else {
+                    to_target / distance * self.speed
+                }
+            }
+        };
+
+        self.position += delta;
+        self.last_delta = delta;
+        delta
+    }
+
+    fn top(&self) -> FixedNumberType {
+        self.position.y - FixedNumberType::new(self.collision_mask.y as i32) / 2
+    }
+
+    fn collides_at(
+        &self,
+        position: Vector2D<FixedNumberType>,
+        collision_mask: Vector2D<u16>,
+    ) -> bool {
+        let half_width = (self.collision_mask.x + collision_mask.x) as i32 / 2;
+        let half_height = (self.collision_mask.y + collision_mask.y) as i32 / 2;
+        let delta = position - self.position;
+
+        delta.x.floor().abs() < half_width && delta.y.floor().abs() < half_height
+    }
+
+    // Whether `entity` is standing on top of this platform, within an
+    // epsilon that covers at least one frame of the platform's own
+    // movement (otherwise a platform moving away from a resting entity at
+    // its configured speed would lose contact with it every frame), with
+    // some horizontal overlap.
+    fn is_supporting(&self, entity: &Entity) -> bool {
+        let vertical_gap = (entity.bottom() - self.top()).abs();
+        let vertical_epsilon = self.speed.abs() + FixedNumberType::new(1);
+        let horizontal_half = (entity.collision_mask.x + self.collision_mask.x) as i32 / 2;
+        let horizontal_overlap =
+            (entity.position.x - self.position.x).floor().abs() < horizontal_half;
+
+        vertical_gap < vertical_epsilon && horizontal_overlap
+    }
+}
+
 struct Map<'a> {
     background: &'a mut Background,
     foreground: &'a mut Background,
@@ -110,16 +287,206 @@ struct Map<'a> {
     level: Level,
 }
 
+impl<'a> Map<'a> {
+    // Centres the camera on `target`, easing towards it, then clamps it so
+    // the screen never shows outside the level (centring instead on any
+    // axis the level is smaller than the screen).
+    fn update_camera_position(&mut self, target: Vector2D<FixedNumberType>) {
+        let target_position = target - (WIDTH / 2, HEIGHT / 2).into();
+        self.position += (target_position - self.position) / 8;
+        self.position = self.clamp_to_level(self.position);
+
+        let position = self.position.floor();
+        self.background.set_position(position);
+        self.foreground.set_position(position);
+    }
+
+    fn clamp_to_level(&self, position: Vector2D<FixedNumberType>) -> Vector2D<FixedNumberType> {
+        let map_width = self.level.dimensions.x as i32 * 8;
+        let map_height = self.level.dimensions.y as i32 * 8;
+
+        let x = if map_width <= WIDTH {
+            FixedNumberType::new((map_width - WIDTH) / 2)
+        } else {
+            clamp(position.x, 0.into(), (map_width - WIDTH).into())
+        };
+
+        let y = if map_height <= HEIGHT {
+            FixedNumberType::new((map_height - HEIGHT) / 2)
+        } else {
+            clamp(position.y, 0.into(), (map_height - HEIGHT).into())
+        };
+
+        (x, y).into()
+    }
+}
+
+fn clamp(value: FixedNumberType, min: FixedNumberType, max: FixedNumberType) -> FixedNumberType {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+// Property values a tile's entry in the `collision` table can hold, beyond
+// the plain `COLLISION_TILE` flag. These line up with the pxmap-style slope
+// tiles authored into the collision table alongside the level data.
+const TILE_SLOPE_UP_RIGHT: u32 = 1;
+const TILE_SLOPE_UP_LEFT: u32 = 2;
+const TILE_SLOPE_UP_RIGHT_LOWER_HALF: u32 = 3;
+const TILE_SLOPE_UP_RIGHT_UPPER_HALF: u32 = 4;
+const TILE_SLOPE_UP_LEFT_LOWER_HALF: u32 = 5;
+const TILE_SLOPE_UP_LEFT_UPPER_HALF: u32 = 6;
+// Marks the tile that completes the level when the wizard reaches it.
+const TILE_GOAL: u32 = 7;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TileKind {
+    Empty,
+    Solid,
+    SlopeUpRight,
+    SlopeUpLeft,
+    SlopeUpRightLowerHalf,
+    SlopeUpRightUpperHalf,
+    SlopeUpLeftLowerHalf,
+    SlopeUpLeftUpperHalf,
+}
+
+impl TileKind {
+    fn from_raw(raw: u32) -> Self {
+        if raw == map_tiles::tilemap::COLLISION_TILE as u32 {
+            TileKind::Solid
+        } else if raw == TILE_SLOPE_UP_RIGHT {
+            TileKind::SlopeUpRight
+        } else if raw == TILE_SLOPE_UP_LEFT {
+            TileKind::SlopeUpLeft
+        } else if raw == TILE_SLOPE_UP_RIGHT_LOWER_HALF {
+            TileKind::SlopeUpRightLowerHalf
+        } else if raw == TILE_SLOPE_UP_RIGHT_UPPER_HALF {
+            TileKind::SlopeUpRightUpperHalf
+        } else if raw == TILE_SLOPE_UP_LEFT_LOWER_HALF {
+            TileKind::SlopeUpLeftLowerHalf
+        } else if raw == TILE_SLOPE_UP_LEFT_UPPER_HALF {
+            TileKind::SlopeUpLeftUpperHalf
+        } else {
+            TileKind::Empty
+        }
+    }
+
+    // The local y at which the tile becomes solid for a given local x,
+    // both measured in pixels within the 8x8 tile. A point is solid when
+    // its local y is greater than or equal to this height.
+    fn surface_height(self, x_local: i32) -> i32 {
+        match self {
+            TileKind::Empty => 8,
+            TileKind::Solid => 0,
+            TileKind::SlopeUpRight => 8 - x_local,
+            TileKind::SlopeUpLeft => x_local,
+            TileKind::SlopeUpRightLowerHalf => 8 - x_local / 2,
+            TileKind::SlopeUpRightUpperHalf => 4 - x_local / 2,
+            TileKind::SlopeUpLeftLowerHalf => 4 + x_local / 2,
+            TileKind::SlopeUpLeftUpperHalf => x_local / 2,
+        }
+    }
+}
+
 impl Level {
-    fn collides(&self, x: i32, y: i32) -> bool {
+    fn tile_kind(&self, x: i32, y: i32) -> TileKind {
         if (x < 0 || x >= self.dimensions.x as i32) || (y < 0 || y >= self.dimensions.y as i32) {
-            return true;
+            return TileKind::Solid;
         }
         let pos = (self.dimensions.x as i32 * y + x) as usize;
         let tile_foreground = self.foreground[pos];
-        let tile_background = self.background[pos];
-        let foreground_tile_property = self.collision[tile_foreground as usize];
-        foreground_tile_property == map_tiles::tilemap::COLLISION_TILE as u32
+        TileKind::from_raw(self.collision[tile_foreground as usize])
+    }
+
+    fn is_goal(&self, x: i32, y: i32) -> bool {
+        if (x < 0 || x >= self.dimensions.x as i32) || (y < 0 || y >= self.dimensions.y as i32) {
+            return false;
+        }
+        let pos = (self.dimensions.x as i32 * y + x) as usize;
+        let tile_foreground = self.foreground[pos];
+        self.collision[tile_foreground as usize] == TILE_GOAL
+    }
+}
+
+// One compiled level's static map data. Building a `LevelData` table from
+// the per-level build-time includes means adding a level is just adding an
+// entry here.
+struct LevelData {
+    background: &'static [u16],
+    foreground: &'static [u16],
+    dimensions: (u32, u32),
+    collision: &'static [u32],
+}
+
+impl LevelData {
+    fn open(&self) -> Level {
+        Level {
+            background: self.background,
+            foreground: self.foreground,
+            dimensions: self.dimensions.into(),
+            collision: self.collision,
+        }
+    }
+}
+
+static LEVELS: &[LevelData] = &[
+    LevelData {
+        background: &map_tiles::level1::TILEMAP,
+        foreground: &map_tiles::level1::BACKGROUND,
+        dimensions: (map_tiles::level1::WIDTH, map_tiles::level1::HEIGHT),
+        collision: &map_tiles::tilemap::TILE_DATA,
+    },
+    LevelData {
+        background: &map_tiles::level2::TILEMAP,
+        foreground: &map_tiles::level2::BACKGROUND,
+        dimensions: (map_tiles::level2::WIDTH, map_tiles::level2::HEIGHT),
+        collision: &map_tiles::tilemap::TILE_DATA,
+    },
+];
+
+// Tracks which compiled level is current and hands out a fresh
+// `PlayingLevel` for it, so transitioning just tears down and reopens.
+// The RNG and music box live here rather than on `PlayingLevel` itself so
+// that the deterministic stream and crossfade state carry over a level
+// transition instead of being reseeded/restarted from scratch.
+struct GameState {
+    current_level: usize,
+    rng: Rng,
+    music_box: MusicBox,
+}
+
+impl GameState {
+    fn new() -> Self {
+        GameState {
+            current_level: 0,
+            rng: Rng::new(0x5EED_1234),
+            music_box: MusicBox::new(),
+        }
+    }
+
+    fn advance(&mut self) {
+        self.current_level = (self.current_level + 1) % LEVELS.len();
+    }
+
+    fn open_current_level<'a>(
+        &self,
+        object_control: &'a ObjectControl,
+        background: &'a mut Background,
+        foreground: &'a mut Background,
+        input: ButtonController,
+    ) -> PlayingLevel<'a> {
+        PlayingLevel::open_level(
+            LEVELS[self.current_level].open(),
+            object_control,
+            background,
+            foreground,
+            input,
+        )
     }
 }
 
@@ -150,6 +517,26 @@ fn ping_pong(i: i32, n: i32) -> i32 {
     }
 }
 
+// Shared by every hat-catching transition, thrown or recalled, so the
+// effect and sound line up with the hat actually landing back on the head.
+fn on_hat_caught(
+    carets: &mut Carets,
+    position: Vector2D<FixedNumberType>,
+    mixer: &mut Mixer,
+    music_box: &MusicBox,
+    rng: &mut Rng,
+) {
+    carets.spawn(
+        position,
+        (0, 0).into(),
+        object_tiles::CARET_SPARKLE_START,
+        4,
+        16,
+        false,
+    );
+    music_box.catch(mixer, rng);
+}
+
 impl<'a> Player<'a> {
     fn new(controller: &'a ObjectControl) -> Self {
         let mut hat = Entity::new(controller, (16_u16, 16_u16).into());
@@ -178,7 +565,30 @@ impl<'a> Player<'a> {
         }
     }
 
-    fn update_frame(&mut self, input: &ButtonController, timer: i32, level: &Level) {
+    // Carries the wizard and hat along with any platform they are resting
+    // on top of, before the normal velocity integration for the frame.
+    fn carry_on_platforms(&mut self, platforms: &[Platform]) {
+        for platform in platforms {
+            if platform.is_supporting(&self.wizard) {
+                self.wizard.position += platform.last_delta;
+            }
+            if platform.is_supporting(&self.hat) {
+                self.hat.position += platform.last_delta;
+            }
+        }
+    }
+
+    fn update_frame(
+        &mut self,
+        input: &ButtonController,
+        timer: i32,
+        level: &Level,
+        platforms: &[Platform],
+        carets: &mut Carets,
+        mixer: &mut Mixer,
+        music_box: &MusicBox,
+        rng: &mut Rng,
+    ) {
         // throw or recall
         if input.is_just_pressed(Button::A) {
             if self.hat_state == HatState::OnHead {
@@ -191,6 +601,16 @@ impl<'a> Player<'a> {
                     }
                     self.hat.velocity = velocity;
                     self.hat_state = HatState::Thrown;
+
+                    carets.spawn(
+                        self.wizard.position,
+                        (0, 0).into(),
+                        object_tiles::CARET_PUFF_START,
+                        4,
+                        16,
+                        false,
+                    );
+                    music_box.throw(mixer, rng);
                 }
             } else if self.hat_state == HatState::Thrown {
                 self.hat.velocity = (0, 0).into();
@@ -206,7 +626,21 @@ impl<'a> Player<'a> {
             self.wizard.velocity.x += FixedNumberType::new(input.x_tri() as i32) / 64;
 
             self.wizard.velocity = self.wizard.velocity * 62 / 64;
-            self.wizard.velocity = self.wizard.update_position(level);
+            let velocity_before_move = self.wizard.velocity;
+            self.wizard.velocity = self.wizard.update_position(level, platforms);
+
+            if velocity_before_move.y > FixedNumberType::new(2)
+                && self.wizard.velocity.y.abs() < FixedNumberType::new(1) / 16
+            {
+                carets.spawn(
+                    self.wizard.position + (0, 8).into(),
+                    (0, 0).into(),
+                    object_tiles::CARET_DUST_START,
+                    4,
+                    12,
+                    false,
+                );
+            }
 
             if self.wizard.velocity.x.abs() > FixedNumberType::new(1) / 16 {
                 let offset = (ping_pong(timer / 16, 4)) as u16;
@@ -280,12 +714,13 @@ impl<'a> Player<'a> {
                 } else {
                     self.hat.velocity += direction / 4;
                 }
-                self.hat.velocity = self.hat.update_position(level);
+                self.hat.velocity = self.hat.update_position(level, platforms);
                 if distance > 16.into() {
                     self.hat_left_range = true;
                 }
                 if self.hat_left_range && distance < 16.into() {
                     self.hat_state = HatState::OnHead;
+                    on_hat_caught(carets, self.hat.position, mixer, music_box, rng);
                 }
             }
             HatState::OnHead => {
@@ -305,10 +740,11 @@ impl<'a> Player<'a> {
                     let v = self.wizard.velocity.magnitude() + 1;
                     self.wizard.velocity = distance_vector / distance * v;
                 }
-                self.wizard.velocity = self.wizard.update_position(level);
+                self.wizard.velocity = self.wizard.update_position(level, platforms);
                 if distance < 16.into() {
                     self.wizard.velocity = self.wizard.velocity / 8;
                     self.hat_state = HatState::OnHead;
+                    on_hat_caught(carets, self.hat.position, mixer, music_box, rng);
                 }
             }
         }
@@ -320,6 +756,9 @@ struct PlayingLevel<'a> {
     background: Map<'a>,
     input: ButtonController,
     player: Player<'a>,
+    platforms: [Platform; 1],
+    carets: Carets<'a>,
+    complete: bool,
 }
 
 impl<'a> PlayingLevel<'a> {
@@ -347,18 +786,55 @@ impl<'a> PlayingLevel<'a> {
             },
             player: Player::new(object_control),
             input,
+            platforms: [Platform::new(
+                (8 * 4, 8 * 10).into(),
+                (8 * 12, 8 * 10).into(),
+                FixedNumberType::new(1),
+                (16_u16, 8_u16).into(),
+            )],
+            carets: Carets::new(object_control),
+            complete: false,
         }
     }
 
-    fn update_frame(&mut self) {
+    fn update_frame(&mut self, mixer: &mut Mixer, music_box: &mut MusicBox, rng: &mut Rng) {
         self.timer += 1;
         self.input.update();
+        music_box.after_blank(mixer);
 
-        self.player
-            .update_frame(&self.input, self.timer, &self.background.level);
+        for platform in &mut self.platforms {
+            platform.update_frame();
+        }
+        self.player.carry_on_platforms(&self.platforms);
+
+        self.player.update_frame(
+            &self.input,
+            self.timer,
+            &self.background.level,
+            &self.platforms,
+            &mut self.carets,
+            mixer,
+            music_box,
+            rng,
+        );
+        self.carets.update_frame();
+
+        self.background
+            .update_camera_position(self.player.wizard.position);
 
         self.player.wizard.commit_position(self.background.position);
         self.player.hat.commit_position(self.background.position);
+        self.carets.commit_position(self.background.position);
+
+        let wizard_tile_x = self.player.wizard.position.x.floor() / 8;
+        let wizard_tile_y = self.player.wizard.position.y.floor() / 8;
+        if self.background.level.is_goal(wizard_tile_x, wizard_tile_y) {
+            self.complete = true;
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
     }
 }
 
@@ -377,23 +853,42 @@ pub fn main() -> ! {
     let mut foreground = tiled.get_background().unwrap();
     object.enable();
 
-    let mut level = PlayingLevel::open_level(
-        Level {
-            background: &map_tiles::level1::TILEMAP,
-            foreground: &map_tiles::level1::BACKGROUND,
-            dimensions: (map_tiles::level1::WIDTH, map_tiles::level1::HEIGHT).into(),
-            collision: &map_tiles::tilemap::TILE_DATA,
-        },
+    let vblank = agb.display.vblank.get();
+    let mut mixer = agb.sound.mixer.mixer();
+    mixer.enable();
+
+    let mut game_state = GameState::new();
+    let mut level = game_state.open_current_level(
         &object,
         &mut background,
         &mut foreground,
         agb::input::ButtonController::new(),
     );
 
-    let vblank = agb.display.vblank.get();
+    const LEVEL_TRANSITION_BLANK_FRAMES: u32 = 30;
 
     loop {
-        level.update_frame();
+        level.update_frame(&mut mixer, &mut game_state.music_box, &mut game_state.rng);
+        mixer.frame();
+
+        if level.is_complete() {
+            game_state.advance();
+
+            // Pause for LEVEL_TRANSITION_BLANK_FRAMES frames before opening
+            // the next level. The screen is not blanked or faded: the last
+            // frame of the finished level stays visible during the pause.
+            for _ in 0..LEVEL_TRANSITION_BLANK_FRAMES {
+                vblank.wait_for_VBlank();
+            }
+
+            level = game_state.open_current_level(
+                &object,
+                &mut background,
+                &mut foreground,
+                agb::input::ButtonController::new(),
+            );
+        }
+
         vblank.wait_for_VBlank();
     }
 }
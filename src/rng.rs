@@ -0,0 +1,28 @@
+// A small xorshift PRNG. Deterministic given a seed, so gameplay driven by
+// it stays reproducible between runs, which matters for debugging on
+// hardware where we can't easily pull entropy from the environment.
+pub struct Rng {
+    state: u32,
+}
+
+impl Rng {
+    pub fn new(seed: u32) -> Self {
+        Rng {
+            // xorshift never recovers from a zero state
+            state: seed | 1,
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    pub fn next_range(&mut self, n: u32) -> u32 {
+        self.next_u32() % n
+    }
+}
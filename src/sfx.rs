@@ -1,4 +1,12 @@
-use agb::sound::mixer::{Mixer, SoundChannel};
+use agb::{
+    number::Num,
+    sound::mixer::{ChannelId, Mixer, SoundChannel},
+};
+
+use crate::rng::Rng;
+
+// Fractional volume, 0 to 1.
+type Volume = Num<i16, 8>;
 
 mod music_data {
     // From the open game art page:
@@ -32,37 +40,94 @@ mod effects {
 
 pub struct MusicBox {
     frame: i32,
+    fade_frames: i16,
+    trigger_point: i32,
+    loop_point: i32,
+    current_loop: Option<ChannelId>,
+    fading_out: Option<(ChannelId, i16)>,
 }
 
 impl MusicBox {
     pub fn new() -> Self {
-        MusicBox { frame: 0 }
+        MusicBox {
+            frame: 0,
+            fade_frames: 12,
+            trigger_point: music_data::TRIGGER_MUSIC_POINT,
+            loop_point: music_data::LOOP_MUSIC,
+            current_loop: None,
+            fading_out: None,
+        }
     }
 
     pub fn after_blank(&mut self, mixer: &mut Mixer) {
         if self.frame == 0 {
             // play the introduction
-            mixer.play_sound(SoundChannel::new(music_data::INTRO_MUSIC));
-        } else if self.frame == music_data::TRIGGER_MUSIC_POINT
-            || (self.frame - music_data::TRIGGER_MUSIC_POINT) % music_data::LOOP_MUSIC == 0
+            self.current_loop = Some(mixer.play_sound(SoundChannel::new(music_data::INTRO_MUSIC)));
+        } else if self.frame == self.trigger_point
+            || (self.frame - self.trigger_point) % self.loop_point == 0
         {
-            mixer.play_sound(SoundChannel::new(music_data::LOOP));
+            let new_loop = mixer.play_sound(SoundChannel::new(music_data::LOOP));
+            if let Some(old_loop) = self.current_loop.replace(new_loop) {
+                self.fading_out = Some((old_loop, self.fade_frames));
+            }
         }
 
+        self.update_fade(mixer);
+
         self.frame += 1;
     }
 
-    pub fn catch(&self, mixer: &mut Mixer) {
-        self.play_random(mixer, effects::CATCHES);
+    // Ramps the previous loop instance's volume down over `fade_frames`
+    // frames instead of letting it play out raw underneath the new one.
+    fn update_fade(&mut self, mixer: &mut Mixer) {
+        let fade_frames = self.fade_frames;
+        if let Some((channel_id, frames_left)) = &mut self.fading_out {
+            if let Some(channel) = mixer.channel(channel_id) {
+                let volume = Volume::new(*frames_left) / fade_frames;
+                channel.volume(volume);
+            }
+
+            *frames_left -= 1;
+            if *frames_left <= 0 {
+                if let Some(channel) = mixer.channel(channel_id) {
+                    channel.stop();
+                }
+                self.fading_out = None;
+            }
+        }
+    }
+
+    pub fn stop(&mut self, mixer: &mut Mixer) {
+        if let Some(channel_id) = self.current_loop.take() {
+            if let Some(channel) = mixer.channel(&channel_id) {
+                channel.stop();
+            }
+        }
+        if let Some((channel_id, _)) = self.fading_out.take() {
+            if let Some(channel) = mixer.channel(&channel_id) {
+                channel.stop();
+            }
+        }
+    }
+
+    pub fn set_volume(&mut self, mixer: &mut Mixer, volume: Volume) {
+        if let Some(channel_id) = &self.current_loop {
+            if let Some(channel) = mixer.channel(channel_id) {
+                channel.volume(volume);
+            }
+        }
+    }
+
+    pub fn catch(&self, mixer: &mut Mixer, rng: &mut Rng) {
+        self.play_random(mixer, rng, effects::CATCHES);
     }
 
-    pub fn throw(&self, mixer: &mut Mixer) {
-        self.play_random(mixer, effects::WHOOSHES);
+    pub fn throw(&self, mixer: &mut Mixer, rng: &mut Rng) {
+        self.play_random(mixer, rng, effects::WHOOSHES);
     }
 
-    fn play_random(&self, mixer: &mut Mixer, effect: &[&'static [u8]]) {
-        mixer.play_sound(SoundChannel::new(
-            effect[(self.frame as usize) % effect.len()],
-        ));
+    fn play_random(&self, mixer: &mut Mixer, rng: &mut Rng, effect: &[&'static [u8]]) {
+        let index = rng.next_range(effect.len() as u32) as usize;
+        mixer.play_sound(SoundChannel::new(effect[index]));
     }
 }